@@ -9,8 +9,8 @@ use dprint_core::{
     },
 };
 use markup_fmt::{
-    FormatError, Hints,
-    config::{FormatOptions, Quotes, ScriptFormatter},
+    FormatError, FormatReportFormatter, Hints,
+    config::{FormatOptions, NewlineStyle, Quotes, ScriptFormatter},
     detect_language, format_text,
 };
 
@@ -54,14 +54,48 @@ impl SyncPluginHandler<FormatOptions> for MarkupFmtPluginHandler {
         request: SyncFormatRequest<FormatOptions>,
         mut format_with_host: impl FnMut(SyncHostFormatRequest) -> FormatResult,
     ) -> FormatResult {
+        let report_coverage = request.config.report_coverage;
+        let coverage_gaps = std::cell::RefCell::new(Vec::new());
+        let total_embedded_blocks = std::cell::Cell::new(0u32);
+
         // falling back to HTML allows to format files with unknown extensions, such as .svg
-        let language = detect_language(request.file_path).unwrap_or(markup_fmt::Language::Html);
+        let language = detect_language(request.file_path).unwrap_or_else(|| {
+            if report_coverage {
+                coverage_gaps.borrow_mut().push(CoverageGap {
+                    lang: request
+                        .file_path
+                        .extension()
+                        .map(|ext| ext.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    source_range: 0..request.file_bytes.len() as u32,
+                    reason: GapReason::ParseOnlyFallback,
+                });
+            }
+            markup_fmt::Language::Html
+        });
+        let range = request.range.clone();
+        let source_text = std::str::from_utf8(&request.file_bytes)?;
+        let terminator = resolve_newline_terminator(request.config.newline_style, source_text);
 
         let format_result = format_text(
-            std::str::from_utf8(&request.file_bytes)?,
+            source_text,
             language,
             request.config,
+            range.clone(),
             |code, hints| {
+                let host_range = translate_range(
+                    range.as_ref(),
+                    source_text,
+                    code,
+                    hints.source_range.clone(),
+                    hints.indent_level,
+                );
+                if range.is_some() && host_range.is_none() {
+                    return Ok(code.into());
+                }
+                if report_coverage {
+                    total_embedded_blocks.set(total_embedded_blocks.get() + 1);
+                }
                 let mut file_name = request
                     .file_path
                     .file_name()
@@ -70,37 +104,88 @@ impl SyncPluginHandler<FormatOptions> for MarkupFmtPluginHandler {
                 file_name.push("#.");
                 file_name.push(hints.ext);
                 let additional_config = build_additional_config(hints, request.config);
+                let ext = hints.ext.to_string();
+                let source_range = hints.source_range.clone();
                 format_with_host(SyncHostFormatRequest {
                     file_path: &request.file_path.with_file_name(file_name),
                     file_bytes: code.as_bytes(),
-                    range: None,
+                    range: host_range,
                     override_config: &additional_config,
                 })
                 .and_then(|result| match result {
                     Some(code) => String::from_utf8(code)
-                        .map(|s| s.into())
+                        .map(|s| normalize_newlines(&s, terminator).into())
                         .map_err(anyhow::Error::from),
-                    None => Ok(code.into()),
+                    None => {
+                        if report_coverage {
+                            let reason = if ext.is_empty() {
+                                GapReason::UnknownEmbeddedLang
+                            } else {
+                                GapReason::NoHostFormatter
+                            };
+                            coverage_gaps.borrow_mut().push(CoverageGap {
+                                lang: ext,
+                                source_range,
+                                reason,
+                            });
+                        }
+                        Ok(normalize_newlines(code, terminator).into())
+                    }
                 })
             },
         );
+        if report_coverage {
+            let gaps = coverage_gaps.into_inner();
+            let total_blocks = total_embedded_blocks.get();
+            if total_blocks > 0 || !gaps.is_empty() {
+                eprint!(
+                    "{}",
+                    format_coverage_report_jsonl(request.file_path, total_blocks, &gaps)
+                );
+            }
+        }
         match format_result {
             Ok(code) => Ok(Some(code.into_bytes())),
             Err(FormatError::Syntax(err)) => Err(err.into()),
-            Err(FormatError::External(errors)) => {
-                let msg = errors.into_iter().fold(
-                    String::from("failed to format code with external formatter:\n"),
-                    |mut msg, error| {
-                        msg.push_str(&format!("{error}\n"));
-                        msg
-                    },
-                );
-                Err(anyhow::anyhow!(msg))
+            // `report` carries one entry per failed embedded formatter: the
+            // synthesized host file path, the embedded language, the byte
+            // offset where that block started in this document, and the
+            // underlying error. `FormatReportFormatter` renders that into
+            // the same human-readable shape this handler used to build by
+            // hand, so tooling that wants the structured entries can still
+            // match on `report` directly instead of parsing this string.
+            Err(FormatError::External(report)) => Err(anyhow::anyhow!(
+                "{}",
+                external_formatter_error_message(FormatReportFormatter::new(&report))
+            )),
+            // Only reachable when `stability_check` is enabled: markup_fmt
+            // re-ran the formatter on its own output and the second pass
+            // diverged from the first. `diff` is the byte range of the first
+            // line range that differs between the two passes, not a full
+            // diff of the document.
+            Err(FormatError::Unstable(diff)) => {
+                Err(anyhow::anyhow!("{}", unstable_format_error_message(diff)))
             }
         }
     }
 }
 
+/// Builds the error message for [`FormatError::External`]. Takes
+/// `impl Display` rather than `FormatReportFormatter` directly so the
+/// message template itself stays testable with a plain string stand-in,
+/// without having to construct a real `FormatReport` from outside this crate.
+fn external_formatter_error_message(report: impl std::fmt::Display) -> String {
+    format!("failed to format code with external formatter:\n{report}")
+}
+
+/// Builds the error message for [`FormatError::Unstable`]. Takes
+/// `impl Display` for the same reason as [`external_formatter_error_message`]:
+/// markup_fmt's `diff` type isn't constructible from this crate, but its
+/// rendered text is all this message needs.
+fn unstable_format_error_message(diff: impl std::fmt::Display) -> String {
+    format!("formatting isn't idempotent, output changed on the second pass:\n{diff}")
+}
+
 #[cfg(target_arch = "wasm32")]
 dprint_core::generate_plugin_code!(
     MarkupFmtPluginHandler,
@@ -108,6 +193,191 @@ dprint_core::generate_plugin_code!(
     FormatOptions
 );
 
+/// A region that was passed through without being formatted. Collected while
+/// `report_coverage` is enabled so a summary can be emitted per document,
+/// analogous to rustfmt's coverage emit mode.
+struct CoverageGap {
+    lang: String,
+    source_range: std::ops::Range<u32>,
+    reason: GapReason,
+}
+
+/// Why a [`CoverageGap`] was left unformatted.
+enum GapReason {
+    /// `hints.ext` named a recognized embedded language, but the host
+    /// (the dprint CLI / editor integration) has no formatter plugin
+    /// configured for it.
+    NoHostFormatter,
+    /// markup_fmt couldn't resolve the embedded block's language at all, so
+    /// there's no host formatter to even look up.
+    UnknownEmbeddedLang,
+    /// `detect_language` didn't recognize the document's own extension, so
+    /// the whole file was parsed as the `Html` catch-all dialect and never
+    /// routed to a host formatter in the first place.
+    ParseOnlyFallback,
+}
+
+impl GapReason {
+    /// Stable, lowercase-kebab token so JSON consumers can match on it
+    /// without parsing prose.
+    fn as_str(&self) -> &'static str {
+        match self {
+            GapReason::NoHostFormatter => "no-host-formatter",
+            GapReason::UnknownEmbeddedLang => "unknown-embedded-lang",
+            GapReason::ParseOnlyFallback => "parse-only-fallback",
+        }
+    }
+}
+
+/// Renders a document's coverage as JSON Lines instead of free text, so CI
+/// can parse and aggregate "X% of this template's embedded blocks were not
+/// formatted". The first line is a summary object carrying `total_blocks`
+/// (every embedded block the document routed to a host formatter, formatted
+/// or not) alongside `gaps` (how many gap lines follow: the embedded blocks
+/// that came back unformatted, plus one whole-document parse-only-fallback
+/// entry when the document's extension wasn't recognized, which is not
+/// counted in `total_blocks`) — the gap list by itself has no denominator to
+/// compute a percentage from. Remaining lines are one object per gap. `dprint_core`'s `FormatResult`
+/// has no diagnostics channel of its own, so this is written straight to
+/// stderr by the caller.
+fn format_coverage_report_jsonl(
+    file_path: &std::path::Path,
+    total_blocks: u32,
+    gaps: &[CoverageGap],
+) -> String {
+    let file = json_string(&file_path.display().to_string());
+    let mut report = format!(
+        "{{\"file\":{file},\"total_blocks\":{total_blocks},\"gaps\":{}}}\n",
+        gaps.len()
+    );
+    for gap in gaps {
+        report.push_str(&format!(
+            "{{\"file\":{file},\"lang\":{},\"start\":{},\"end\":{},\"reason\":{}}}\n",
+            json_string(&gap.lang),
+            gap.source_range.start,
+            gap.source_range.end,
+            json_string(gap.reason.as_str()),
+        ));
+    }
+    report
+}
+
+/// Minimal JSON string escaping for the file paths and language tags that
+/// flow through [`format_coverage_report_jsonl`]; this plugin has no serde
+/// dependency, so this hand-rolls just enough of RFC 8259 to stay valid.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Picks the line terminator embedded host output should be normalized to,
+/// mirroring the `newline_style` resolution markup_fmt itself applies to the
+/// document as a whole. `Auto` counts `\r\n` occurrences in the original
+/// document against lone `\n` occurrences; ties favor `\n`.
+fn resolve_newline_terminator(style: NewlineStyle, source_text: &str) -> &'static str {
+    match style {
+        NewlineStyle::Unix => "\n",
+        NewlineStyle::Windows => "\r\n",
+        // This plugin only ships as the wasm32 binary generated above, so
+        // `cfg!(windows)` would reflect the build target, not the host
+        // machine actually running it, and can't tell us the real native
+        // line ending. `resolve_config` resolves `Native` down to
+        // `Unix`/`Windows` using the host-provided `GlobalConfiguration`
+        // before `FormatOptions` ever reaches here; `\n` is just the
+        // defensive fallback if that normalization is ever skipped.
+        NewlineStyle::Native => "\n",
+        NewlineStyle::Auto => {
+            let crlf = source_text.matches("\r\n").count();
+            let lf = source_text.matches('\n').count() - crlf;
+            if crlf > lf { "\r\n" } else { "\n" }
+        }
+    }
+}
+
+/// Rewrites every line break in `text` to `terminator`, so embedded code
+/// handed back by a host formatter can't reintroduce a different newline
+/// style than the rest of the document.
+fn normalize_newlines(text: &str, terminator: &str) -> String {
+    let unified = if text.contains('\r') {
+        text.replace("\r\n", "\n")
+    } else {
+        text.to_string()
+    };
+    if terminator == "\n" {
+        unified
+    } else {
+        unified.replace('\n', terminator)
+    }
+}
+
+/// Maps a byte offset measured from the start of an embedded block's
+/// original (still-indented) source text to the matching offset in the
+/// de-indented text that's actually handed to the host formatter. Mirrors
+/// the per-line stripping of up to `indent_level` leading bytes that
+/// markup_fmt performs when extracting embedded code.
+fn local_offset(original: &str, indent_level: usize, target: u32) -> u32 {
+    let target = target as usize;
+    let mut consumed = 0;
+    let mut produced = 0u32;
+    for line in original.split_inclusive('\n') {
+        // Only the line's actual content can have indentation stripped from
+        // it, not the trailing newline itself — otherwise a blank line
+        // shorter than `indent_level` would have its newline miscounted as
+        // stripped indentation, shifting every offset after it.
+        let content_len = line.len() - usize::from(line.ends_with('\n'));
+        let stripped = indent_level.min(content_len);
+        if consumed + line.len() >= target {
+            let within = (target - consumed).saturating_sub(stripped);
+            return produced + within as u32;
+        }
+        consumed += line.len();
+        produced += (line.len() - stripped) as u32;
+    }
+    produced
+}
+
+/// Projects the document-level selection range onto an embedded block's own
+/// local coordinate space (the de-indented `code` the host formatter
+/// actually sees), so the host only rewrites the part of the selection that
+/// falls inside that block. Returns `None` when the selection doesn't
+/// overlap the block at all, i.e. the block should be left untouched. The
+/// result is clamped to `code`'s length so an imprecise mapping can never
+/// hand the host an out-of-range slice.
+fn translate_range(
+    range: Option<&std::ops::Range<u32>>,
+    source_text: &str,
+    code: &str,
+    source_range: std::ops::Range<u32>,
+    indent_level: usize,
+) -> Option<std::ops::Range<u32>> {
+    let range = range?;
+    let start = range.start.max(source_range.start);
+    let end = range.end.min(source_range.end);
+    if start >= end {
+        return None;
+    }
+    let original = source_text.get(source_range.start as usize..source_range.end as usize)?;
+    let local_start = local_offset(original, indent_level, start - source_range.start);
+    let local_end = local_offset(original, indent_level, end - source_range.start);
+    let code_len = code.len() as u32;
+    let local_start = local_start.min(code_len);
+    let local_end = local_end.min(code_len);
+    (local_start < local_end).then_some(local_start..local_end)
+}
+
 #[doc(hidden)]
 pub fn build_additional_config(hints: Hints, config: &FormatOptions) -> ConfigKeyMap {
     let mut additional_config = ConfigKeyMap::new();
@@ -149,7 +419,203 @@ pub fn build_additional_config(hints: Hints, config: &FormatOptions) -> ConfigKe
 #[cfg(test)]
 mod tests {
     use super::*;
-    use markup_fmt::config::VueCustomBlock;
+    use markup_fmt::config::{NewlineStyle, VueCustomBlock};
+
+    #[test]
+    fn test_format_coverage_report_jsonl_leads_with_a_total_blocks_summary() {
+        let gaps = vec![
+            CoverageGap {
+                lang: "ts".to_string(),
+                source_range: 12..40,
+                reason: GapReason::NoHostFormatter,
+            },
+            CoverageGap {
+                lang: String::new(),
+                source_range: 80..120,
+                reason: GapReason::UnknownEmbeddedLang,
+            },
+            CoverageGap {
+                lang: "svg".to_string(),
+                source_range: 0..200,
+                reason: GapReason::ParseOnlyFallback,
+            },
+        ];
+
+        let report = format_coverage_report_jsonl(std::path::Path::new("App.vue"), 5, &gaps);
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            r#"{"file":"App.vue","total_blocks":5,"gaps":3}"#
+        );
+        assert_eq!(
+            lines[1],
+            r#"{"file":"App.vue","lang":"ts","start":12,"end":40,"reason":"no-host-formatter"}"#
+        );
+        assert_eq!(
+            lines[2],
+            r#"{"file":"App.vue","lang":"","start":80,"end":120,"reason":"unknown-embedded-lang"}"#
+        );
+        assert_eq!(
+            lines[3],
+            r#"{"file":"App.vue","lang":"svg","start":0,"end":200,"reason":"parse-only-fallback"}"#
+        );
+    }
+
+    #[test]
+    fn test_format_coverage_report_jsonl_summary_only_when_fully_covered() {
+        let report = format_coverage_report_jsonl(std::path::Path::new("App.vue"), 4, &[]);
+
+        assert_eq!(report, "{\"file\":\"App.vue\",\"total_blocks\":4,\"gaps\":0}\n");
+    }
+
+    #[test]
+    fn test_json_string_escapes_quotes_and_backslashes() {
+        assert_eq!(json_string(r#"weird"name\.vue"#), r#""weird\"name\\.vue""#);
+    }
+
+    #[test]
+    fn test_external_formatter_error_message_wraps_report_text() {
+        let message = external_formatter_error_message("app.vue#.ts: unexpected token");
+
+        assert_eq!(
+            message,
+            "failed to format code with external formatter:\napp.vue#.ts: unexpected token"
+        );
+    }
+
+    #[test]
+    fn test_unstable_format_error_message_wraps_diff_text() {
+        let message = unstable_format_error_message("3..8");
+
+        assert_eq!(
+            message,
+            "formatting isn't idempotent, output changed on the second pass:\n3..8"
+        );
+    }
+
+    #[test]
+    fn test_resolve_newline_terminator_auto_picks_dominant_style() {
+        assert_eq!(
+            resolve_newline_terminator(NewlineStyle::Auto, "a\r\nb\r\nc\n"),
+            "\r\n"
+        );
+        assert_eq!(
+            resolve_newline_terminator(NewlineStyle::Auto, "a\nb\nc\r\n"),
+            "\n"
+        );
+        // ties favor "\n"
+        assert_eq!(resolve_newline_terminator(NewlineStyle::Auto, "a\r\nb\n"), "\n");
+    }
+
+    #[test]
+    fn test_resolve_newline_terminator_explicit_styles() {
+        assert_eq!(resolve_newline_terminator(NewlineStyle::Unix, "a\r\nb"), "\n");
+        assert_eq!(
+            resolve_newline_terminator(NewlineStyle::Windows, "a\nb"),
+            "\r\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_newlines_to_windows() {
+        assert_eq!(
+            normalize_newlines("a\nb\r\nc\n", "\r\n"),
+            "a\r\nb\r\nc\r\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_newlines_to_unix() {
+        assert_eq!(normalize_newlines("a\r\nb\nc\r\n", "\n"), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn test_translate_range_rebases_into_local_coordinates() {
+        let source_text = "<script>\n    const a = 1;\n    const b = 2;\n</script>\n";
+        let code = "const a = 1;\nconst b = 2;\n";
+        let source_range = (source_text.find("    const a").unwrap() as u32)
+            ..(source_text.find("</script>").unwrap() as u32);
+
+        let sel_start = source_text.find("const b").unwrap() as u32;
+        let sel_end = sel_start + "const b = 2;".len() as u32;
+
+        let translated = translate_range(
+            Some(&(sel_start..sel_end)),
+            source_text,
+            code,
+            source_range,
+            4,
+        )
+        .expect("selection overlaps the block");
+
+        assert_eq!(
+            &code[translated.start as usize..translated.end as usize],
+            "const b = 2;"
+        );
+    }
+
+    #[test]
+    fn test_translate_range_handles_blank_lines_in_block() {
+        let source_text = "<script>\n    function f() {\n      a();\n\n      b();\n    }\n</script>\n";
+        let code = "function f() {\n  a();\n\n  b();\n}\n";
+        let source_range = (source_text.find("    function f").unwrap() as u32)
+            ..(source_text.find("</script>").unwrap() as u32);
+
+        let sel_start = source_text.find("b();").unwrap() as u32;
+        let sel_end = sel_start + "b();".len() as u32;
+
+        let translated = translate_range(
+            Some(&(sel_start..sel_end)),
+            source_text,
+            code,
+            source_range,
+            4,
+        )
+        .expect("selection overlaps the block");
+
+        assert_eq!(
+            &code[translated.start as usize..translated.end as usize],
+            "b();"
+        );
+    }
+
+    #[test]
+    fn test_translate_range_clamps_to_code_len() {
+        let source_text = "<script>\n    const a = 1;\n</script>\n";
+        let code = "const a = 1;\n";
+        let source_range = (source_text.find("    const a").unwrap() as u32)
+            ..(source_text.find("</script>").unwrap() as u32);
+
+        // selection runs past the end of the block, e.g. "select to end of file"
+        let translated = translate_range(
+            Some(&(0..source_text.len() as u32)),
+            source_text,
+            code,
+            source_range,
+            4,
+        )
+        .expect("selection overlaps the block");
+
+        assert!(translated.end as usize <= code.len());
+    }
+
+    #[test]
+    fn test_translate_range_none_when_selection_outside_block() {
+        let source_text = "<script>\n    const a = 1;\n</script>\n<p>text</p>\n";
+        let code = "const a = 1;\n";
+        let source_range = (source_text.find("    const a").unwrap() as u32)
+            ..(source_text.find("</script>").unwrap() as u32);
+
+        let p_start = source_text.find("<p>").unwrap() as u32;
+        let p_end = source_text.find("</p>").unwrap() as u32 + "</p>".len() as u32;
+
+        assert!(
+            translate_range(Some(&(p_start..p_end)), source_text, code, source_range, 4)
+                .is_none()
+        );
+    }
 
     #[test]
     fn test_resolve_config_vue_custom_block_simple() {
@@ -247,6 +713,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_config_newline_style_default() {
+        let mut handler = MarkupFmtPluginHandler;
+        let config = ConfigKeyMap::new();
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert!(matches!(result.config.newline_style, NewlineStyle::Auto));
+    }
+
+    #[test]
+    fn test_resolve_config_newline_style_explicit() {
+        let mut handler = MarkupFmtPluginHandler;
+        let mut config = ConfigKeyMap::new();
+        config.insert("newlineStyle".into(), "windows".into());
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert!(matches!(result.config.newline_style, NewlineStyle::Windows));
+    }
+
+    #[test]
+    fn test_resolve_config_newline_style_native_resolves_before_format_options() {
+        let mut handler = MarkupFmtPluginHandler;
+        let mut config = ConfigKeyMap::new();
+        config.insert("newlineStyle".into(), "native".into());
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        // `resolve_newline_terminator`'s `Native` arm no longer consults
+        // `cfg!(windows)` because it assumes `resolve_config` already
+        // downgraded `Native` to a concrete `Unix`/`Windows` using the
+        // host-provided `GlobalConfiguration`. Pin that assumption here so a
+        // regression that lets `Native` reach `FormatOptions` unresolved
+        // fails loudly instead of silently emitting `\n` on Windows.
+        assert!(!matches!(result.config.newline_style, NewlineStyle::Native));
+    }
+
+    #[test]
+    fn test_resolve_config_stability_check_default() {
+        let mut handler = MarkupFmtPluginHandler;
+        let config = ConfigKeyMap::new();
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert_eq!(result.config.stability_check, false);
+    }
+
+    #[test]
+    fn test_resolve_config_stability_check_enabled() {
+        let mut handler = MarkupFmtPluginHandler;
+        let mut config = ConfigKeyMap::new();
+        config.insert("stabilityCheck".into(), true.into());
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert_eq!(result.config.stability_check, true);
+    }
+
+    #[test]
+    fn test_resolve_config_report_coverage_default() {
+        let mut handler = MarkupFmtPluginHandler;
+        let config = ConfigKeyMap::new();
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert_eq!(result.config.report_coverage, false);
+    }
+
+    #[test]
+    fn test_resolve_config_report_coverage_enabled() {
+        let mut handler = MarkupFmtPluginHandler;
+        let mut config = ConfigKeyMap::new();
+        config.insert("reportCoverage".into(), true.into());
+
+        let global_config = GlobalConfiguration::default();
+        let result = handler.resolve_config(config, &global_config);
+
+        assert_eq!(result.diagnostics.len(), 0);
+        assert_eq!(result.config.report_coverage, true);
+    }
+
     #[test]
     fn test_resolve_config_invalid_vue_custom_block_value() {
         let mut handler = MarkupFmtPluginHandler;